@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use tauri::AppHandle;
+
+use crate::server::read_env_file;
+
+const DEFAULT_BASE_URL: &str = "http://localhost:7095";
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+const DEFAULT_READINESS_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ServerMode {
+    /// Spawn and supervise the bundled `hyper` binary ourselves.
+    Local,
+    /// Talk to an operator-supplied coordinator; never spawn or kill anything.
+    Remote,
+}
+
+/// Where and how to reach the Hyperion coordinator, read once from
+/// `.env.hyper` at startup. Every command derives its base URL and auth
+/// from this instead of the old hardcoded `localhost:7095` constant.
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    pub mode: ServerMode,
+    pub base_url: String,
+    pub api_key: Option<String>,
+    pub request_timeout: Duration,
+    /// How long `spawn_readiness_watcher` polls before giving up and
+    /// emitting `hyperion://failed`.
+    pub readiness_timeout: Duration,
+}
+
+impl ServerConfig {
+    pub fn from_env(app_handle: &AppHandle) -> Self {
+        let env_vars = read_env_file(app_handle);
+
+        let mode = match env_vars.get("HYPERION_MODE").map(|v| v.to_lowercase()) {
+            Some(ref v) if v == "remote" => ServerMode::Remote,
+            _ => ServerMode::Local,
+        };
+
+        let base_url = env_vars
+            .get("HYPERION_BASE_URL")
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+            .trim_end_matches('/')
+            .to_string();
+
+        let api_key = env_vars.get("HYPERION_API_KEY").cloned();
+
+        let request_timeout = env_vars
+            .get("HYPERION_REQUEST_TIMEOUT_SECS")
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_REQUEST_TIMEOUT);
+
+        let readiness_timeout = env_vars
+            .get("HYPERION_READINESS_TIMEOUT_SECS")
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_READINESS_TIMEOUT);
+
+        Self {
+            mode,
+            base_url,
+            api_key,
+            request_timeout,
+            readiness_timeout,
+        }
+    }
+
+    pub fn is_remote(&self) -> bool {
+        self.mode == ServerMode::Remote
+    }
+
+    pub fn ui_url(&self) -> String {
+        format!("{}/ui", self.base_url)
+    }
+
+    pub fn health_url(&self) -> String {
+        format!("{}/health", self.base_url)
+    }
+
+    pub fn tools_call_url(&self) -> String {
+        format!("{}/api/mcp/tools/call", self.base_url)
+    }
+
+    pub fn tools_list_url(&self) -> String {
+        format!("{}/api/mcp/tools/list", self.base_url)
+    }
+}
@@ -0,0 +1,287 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use futures_util::StreamExt;
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+
+use crate::config::ServerConfig;
+use crate::mcp_client::{get_with_retry, post_for_stream, post_with_retry, response_to_result, McpClient, McpError};
+
+static STREAM_REQUEST_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Builds a request id out of letters only (no digits, slashes or colons)
+/// so it's always safe to splice into a Tauri event name.
+fn next_stream_request_id() -> String {
+    let mut n = STREAM_REQUEST_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let mut suffix = String::new();
+    loop {
+        let digit = (n % 26) as u8;
+        suffix.push((b'a' + digit) as char);
+        n /= 26;
+        if n == 0 {
+            break;
+        }
+        n -= 1;
+    }
+    format!("mcpstream{}", suffix.chars().rev().collect::<String>())
+}
+
+async fn call_tool(
+    client: &McpClient,
+    config: &ServerConfig,
+    name: &str,
+    arguments: serde_json::Value,
+) -> Result<serde_json::Value, McpError> {
+    let payload = serde_json::json!({
+        "name": name,
+        "arguments": arguments
+    });
+
+    let response = post_with_retry(client, &config.tools_call_url(), &payload).await?;
+    response_to_result(response).await
+}
+
+// MCP Tool Commands - Direct access to MCP tools from desktop app
+
+#[tauri::command]
+pub async fn call_mcp_tool(
+    client: State<'_, McpClient>,
+    config: State<'_, ServerConfig>,
+    name: String,
+    arguments: serde_json::Value,
+) -> Result<serde_json::Value, McpError> {
+    call_tool(&client, &config, &name, arguments).await
+}
+
+/// Fetches the coordinator's tool catalog (name, description, argument
+/// JSON-schema) so the frontend can discover and drive any server-side
+/// tool through `call_mcp_tool` without a matching hand-written wrapper.
+#[tauri::command]
+pub async fn list_mcp_tools(
+    client: State<'_, McpClient>,
+    config: State<'_, ServerConfig>,
+) -> Result<serde_json::Value, McpError> {
+    let response = get_with_retry(&client, &config.tools_list_url()).await?;
+    response_to_result(response).await
+}
+
+#[derive(Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum StreamEvent {
+    Chunk { data: serde_json::Value },
+    Done,
+    Error { message: String },
+}
+
+/// Issues a tool call expecting an SSE response and forwards the `data:`
+/// payload of each newline-delimited line to the frontend as it arrives
+/// (lines without a `data:` prefix are not forwarded), instead of
+/// `call_mcp_tool`'s await-the-whole-body behavior. Returns the request id
+/// immediately so the UI can subscribe to `mcp-stream://<request-id>`
+/// before the first chunk lands.
+#[tauri::command]
+pub fn call_mcp_tool_stream(app_handle: AppHandle, name: String, arguments: serde_json::Value) -> String {
+    let request_id = next_stream_request_id();
+    let event_name = format!("mcp-stream://{request_id}");
+
+    tauri::async_runtime::spawn({
+        let event_name = event_name.clone();
+        async move {
+            let client: State<McpClient> = app_handle.state();
+            let config: State<ServerConfig> = app_handle.state();
+
+            let payload = serde_json::json!({ "name": name, "arguments": arguments });
+            let response = match post_for_stream(&client, &config.tools_call_url(), &payload).await {
+                Ok(response) => response,
+                Err(e) => {
+                    let _ = app_handle.emit_all(&event_name, StreamEvent::Error { message: e.message });
+                    return;
+                }
+            };
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let _ = app_handle.emit_all(
+                    &event_name,
+                    StreamEvent::Error {
+                        message: format!("MCP tool call failed: {status}"),
+                    },
+                );
+                return;
+            }
+
+            // Buffered as raw bytes (not decoded per-chunk) so a multi-byte
+            // UTF-8 character split across a chunk boundary is reassembled
+            // before we ever try to decode it.
+            let mut buffer: Vec<u8> = Vec::new();
+            let mut body = response.bytes_stream();
+
+            while let Some(chunk) = body.next().await {
+                let bytes = match chunk {
+                    Ok(bytes) => bytes,
+                    Err(e) => {
+                        let _ = app_handle.emit_all(&event_name, StreamEvent::Error { message: e.to_string() });
+                        return;
+                    }
+                };
+
+                buffer.extend_from_slice(&bytes);
+
+                while let Some(newline) = buffer.iter().position(|b| *b == b'\n') {
+                    let line: Vec<u8> = buffer.drain(..=newline).collect();
+                    let line = String::from_utf8_lossy(&line[..line.len() - 1]);
+                    emit_stream_line(&app_handle, &event_name, line.trim());
+                }
+            }
+
+            if !buffer.is_empty() {
+                let line = String::from_utf8_lossy(&buffer);
+                emit_stream_line(&app_handle, &event_name, line.trim());
+            }
+
+            let _ = app_handle.emit_all(&event_name, StreamEvent::Done);
+        }
+    });
+
+    request_id
+}
+
+fn emit_stream_line(app_handle: &AppHandle, event_name: &str, line: &str) {
+    // Only `data:` payload lines carry content; SSE framing like `event:`,
+    // `id:`, `retry:`, and `:`-prefixed keep-alive comments are ignored.
+    let Some(line) = line.strip_prefix("data:").map(str::trim) else {
+        return;
+    };
+    if line.is_empty() {
+        return;
+    }
+
+    let data = serde_json::from_str(line).unwrap_or_else(|_| serde_json::Value::String(line.to_string()));
+    let _ = app_handle.emit_all(event_name, StreamEvent::Chunk { data });
+}
+
+#[tauri::command]
+pub async fn create_human_task(
+    client: State<'_, McpClient>,
+    config: State<'_, ServerConfig>,
+    prompt: String,
+) -> Result<serde_json::Value, McpError> {
+    call_tool(&client, &config, "coordinator_create_human_task", serde_json::json!({ "prompt": prompt })).await
+}
+
+#[tauri::command]
+pub async fn create_agent_task(
+    client: State<'_, McpClient>,
+    config: State<'_, ServerConfig>,
+    human_task_id: String,
+    agent_name: String,
+    role: String,
+    context_summary: Option<String>,
+    files_modified: Option<Vec<String>>,
+    todos: Option<Vec<serde_json::Value>>,
+) -> Result<serde_json::Value, McpError> {
+    let mut args = serde_json::json!({
+        "humanTaskId": human_task_id,
+        "agentName": agent_name,
+        "role": role
+    });
+
+    if let Some(summary) = context_summary {
+        args["contextSummary"] = serde_json::Value::String(summary);
+    }
+    if let Some(files) = files_modified {
+        args["filesModified"] = serde_json::json!(files);
+    }
+    if let Some(todos_list) = todos {
+        args["todos"] = serde_json::json!(todos_list);
+    }
+
+    call_tool(&client, &config, "coordinator_create_agent_task", args).await
+}
+
+#[tauri::command]
+pub async fn list_human_tasks(
+    client: State<'_, McpClient>,
+    config: State<'_, ServerConfig>,
+) -> Result<serde_json::Value, McpError> {
+    call_tool(&client, &config, "coordinator_list_human_tasks", serde_json::json!({})).await
+}
+
+#[tauri::command]
+pub async fn list_agent_tasks(
+    client: State<'_, McpClient>,
+    config: State<'_, ServerConfig>,
+    agent_name: Option<String>,
+    human_task_id: Option<String>,
+) -> Result<serde_json::Value, McpError> {
+    let mut args = serde_json::json!({});
+
+    if let Some(name) = agent_name {
+        args["agentName"] = serde_json::Value::String(name);
+    }
+    if let Some(id) = human_task_id {
+        args["humanTaskId"] = serde_json::Value::String(id);
+    }
+
+    call_tool(&client, &config, "coordinator_list_agent_tasks", args).await
+}
+
+#[tauri::command]
+pub async fn update_task_status(
+    client: State<'_, McpClient>,
+    config: State<'_, ServerConfig>,
+    task_id: String,
+    status: String,
+    notes: Option<String>,
+) -> Result<serde_json::Value, McpError> {
+    let mut args = serde_json::json!({
+        "taskId": task_id,
+        "status": status
+    });
+
+    if let Some(notes_text) = notes {
+        args["notes"] = serde_json::Value::String(notes_text);
+    }
+
+    call_tool(&client, &config, "coordinator_update_task_status", args).await
+}
+
+#[tauri::command]
+pub async fn upsert_knowledge(
+    client: State<'_, McpClient>,
+    config: State<'_, ServerConfig>,
+    collection: String,
+    text: String,
+    metadata: Option<serde_json::Value>,
+) -> Result<serde_json::Value, McpError> {
+    let mut args = serde_json::json!({
+        "collection": collection,
+        "text": text
+    });
+
+    if let Some(meta) = metadata {
+        args["metadata"] = meta;
+    }
+
+    call_tool(&client, &config, "coordinator_upsert_knowledge", args).await
+}
+
+#[tauri::command]
+pub async fn query_knowledge(
+    client: State<'_, McpClient>,
+    config: State<'_, ServerConfig>,
+    collection: String,
+    query: String,
+    limit: Option<i32>,
+) -> Result<serde_json::Value, McpError> {
+    let mut args = serde_json::json!({
+        "collection": collection,
+        "query": query
+    });
+
+    if let Some(lim) = limit {
+        args["limit"] = serde_json::json!(lim);
+    }
+
+    call_tool(&client, &config, "coordinator_query_knowledge", args).await
+}
@@ -0,0 +1,164 @@
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use serde::Serialize;
+
+use crate::config::ServerConfig;
+
+const MAX_RETRIES: u32 = 3;
+const RETRY_BASE_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+/// Streamed tool calls (indexing, large queries) can run far longer than a
+/// normal request/response round trip, so they get their own generous
+/// per-request timeout instead of the client's default.
+const STREAM_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+
+/// Shared HTTP client for talking to the Hyperion coordinator, built once
+/// with a request timeout and (if configured) a bearer token, and managed
+/// as Tauri state so every command reuses the same connection pool.
+pub struct McpClient {
+    pub http: reqwest::Client,
+}
+
+impl McpClient {
+    pub fn from_config(config: &ServerConfig) -> Self {
+        let mut headers = HeaderMap::new();
+        if let Some(api_key) = &config.api_key {
+            if let Ok(value) = HeaderValue::from_str(&format!("Bearer {api_key}")) {
+                headers.insert(AUTHORIZATION, value);
+            } else {
+                eprintln!("Ignoring HYPERION_API_KEY: not a valid header value");
+            }
+        }
+
+        let http = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .default_headers(headers)
+            .build()
+            .expect("failed to build Hyperion HTTP client");
+
+        Self { http }
+    }
+}
+
+/// Structured error surfaced to the frontend in place of a bare string, so
+/// the UI can distinguish a rate limit from a broken payload from a dead
+/// server without parsing prose.
+#[derive(Debug, Clone, Serialize)]
+pub struct McpError {
+    pub code: String,
+    pub message: String,
+    #[serde(rename = "retryAfter", skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+}
+
+impl McpError {
+    fn new(code: &str, message: impl Into<String>) -> Self {
+        Self {
+            code: code.to_string(),
+            message: message.into(),
+            retry_after: None,
+        }
+    }
+}
+
+/// `reqwest::Client::send` only returns `Err` for transport-level failures
+/// (connect, timeout, TLS, body errors) — a 502/503 response comes back as
+/// `Ok(Response)`, so that case is handled separately by `is_retryable_status`.
+fn is_transient(error: &reqwest::Error) -> bool {
+    error.is_timeout() || error.is_connect()
+}
+
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    status.as_u16() == 502 || status.as_u16() == 503
+}
+
+/// Sends whatever `build` constructs, retrying connection failures,
+/// timeouts and 502/503 responses a few times with exponential backoff
+/// before giving up.
+async fn send_with_retry<F>(build: F) -> Result<reqwest::Response, McpError>
+where
+    F: Fn() -> reqwest::RequestBuilder,
+{
+    let mut delay = RETRY_BASE_DELAY;
+
+    for attempt in 0..=MAX_RETRIES {
+        match build().send().await {
+            Ok(response) if attempt < MAX_RETRIES && is_retryable_status(response.status()) => {
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            Ok(response) => return Ok(response),
+            Err(e) if attempt < MAX_RETRIES && is_transient(&e) => {
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            Err(e) if e.is_timeout() => {
+                return Err(McpError::new("timeout", e.to_string()));
+            }
+            Err(e) if e.is_connect() => {
+                return Err(McpError::new("connection_failed", e.to_string()));
+            }
+            Err(e) => return Err(McpError::new("request_failed", e.to_string())),
+        }
+    }
+
+    unreachable!("loop always returns within MAX_RETRIES + 1 attempts")
+}
+
+/// POSTs `url` with `body`, retrying connection failures, timeouts and
+/// 502/503s a few times with exponential backoff before giving up.
+pub async fn post_with_retry(
+    client: &McpClient,
+    url: &str,
+    body: &serde_json::Value,
+) -> Result<reqwest::Response, McpError> {
+    send_with_retry(|| client.http.post(url).json(body)).await
+}
+
+/// POSTs `url` with `body` for a streamed response, overriding the client's
+/// default timeout with `STREAM_TIMEOUT` since a long-lived stream must not
+/// be cut off mid-transfer. No retry: a streaming call that's already
+/// started can't be safely replayed.
+pub async fn post_for_stream(
+    client: &McpClient,
+    url: &str,
+    body: &serde_json::Value,
+) -> Result<reqwest::Response, McpError> {
+    client
+        .http
+        .post(url)
+        .json(body)
+        .timeout(STREAM_TIMEOUT)
+        .send()
+        .await
+        .map_err(|e| McpError::new("request_failed", e.to_string()))
+}
+
+/// GETs `url`, retrying connection failures, timeouts and 502/503s a few
+/// times with exponential backoff before giving up.
+pub async fn get_with_retry(client: &McpClient, url: &str) -> Result<reqwest::Response, McpError> {
+    send_with_retry(|| client.http.get(url)).await
+}
+
+pub async fn response_to_result(response: reqwest::Response) -> Result<serde_json::Value, McpError> {
+    let status = response.status();
+    if status.is_success() {
+        response
+            .json()
+            .await
+            .map_err(|e| McpError::new("invalid_response", e.to_string()))
+    } else {
+        let retry_after = response
+            .headers()
+            .get("Retry-After")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        let body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "<no body>".to_string());
+        Err(McpError {
+            code: format!("http_{}", status.as_u16()),
+            message: format!("MCP tool call failed: {status}: {body}"),
+            retry_after,
+        })
+    }
+}
@@ -0,0 +1,215 @@
+use std::process::Child;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Manager, State};
+
+use crate::config::ServerConfig;
+use crate::server::start_hyperion_server;
+
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// How long the server has to stay up before a crash is treated as a fresh
+/// failure again instead of continuing to ramp up the backoff.
+const STABLE_WINDOW: Duration = Duration::from_secs(60);
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// Granularity at which the monitor thread rechecks `supervisor_active`
+/// while "sleeping", so `stop_hyperion_server` never has to wait out a
+/// whole poll interval or backoff before it can join the thread.
+const SLEEP_STEP: Duration = Duration::from_millis(100);
+
+/// Holds the supervised `hyper` child process plus the bookkeeping the
+/// monitor thread needs to decide when and how hard to restart it.
+pub struct HyperionProcess {
+    child: Mutex<Option<Child>>,
+    supervisor_active: AtomicBool,
+    supervisor_handle: Mutex<Option<JoinHandle<()>>>,
+    restart_count: AtomicU32,
+    last_exit_reason: Mutex<Option<String>>,
+}
+
+impl HyperionProcess {
+    pub fn new() -> Self {
+        Self {
+            child: Mutex::new(None),
+            supervisor_active: AtomicBool::new(false),
+            supervisor_handle: Mutex::new(None),
+            restart_count: AtomicU32::new(0),
+            last_exit_reason: Mutex::new(None),
+        }
+    }
+
+    fn set_child(&self, child: Child) {
+        *self.child.lock().unwrap() = Some(child);
+    }
+
+    fn record_exit(&self, reason: String) {
+        *self.last_exit_reason.lock().unwrap() = Some(reason);
+    }
+}
+
+/// Spawns the server and starts the background monitor that restarts it on
+/// unexpected exit. Call once from `setup`; `restart_server` re-enters the
+/// same child slot without spawning a second monitor, because
+/// `stop_hyperion_server` joins the previous monitor thread before this
+/// swaps `supervisor_active` back to `true`.
+pub fn spawn_and_supervise(app_handle: AppHandle) -> Result<(), String> {
+    let process_state: State<HyperionProcess> = app_handle.state();
+    let child = start_hyperion_server(&app_handle)?;
+    process_state.set_child(child);
+
+    if !process_state
+        .supervisor_active
+        .swap(true, Ordering::SeqCst)
+    {
+        let handle = std::thread::spawn({
+            let app_handle = app_handle.clone();
+            move || supervisor_loop(app_handle)
+        });
+        *process_state.supervisor_handle.lock().unwrap() = Some(handle);
+    }
+
+    Ok(())
+}
+
+/// Sleeps in `SLEEP_STEP` increments, bailing out early as soon as `active`
+/// goes false. Returns whether `active` was still true when it finished.
+fn interruptible_sleep(duration: Duration, active: &AtomicBool) -> bool {
+    let mut remaining = duration;
+    while remaining > Duration::ZERO {
+        if !active.load(Ordering::SeqCst) {
+            return false;
+        }
+        let step = remaining.min(SLEEP_STEP);
+        std::thread::sleep(step);
+        remaining = remaining.saturating_sub(step);
+    }
+    active.load(Ordering::SeqCst)
+}
+
+fn supervisor_loop(app_handle: AppHandle) {
+    let process_state: State<HyperionProcess> = app_handle.state();
+    let mut backoff = INITIAL_BACKOFF;
+    let mut last_restart = Instant::now();
+
+    while process_state.supervisor_active.load(Ordering::SeqCst) {
+        if !interruptible_sleep(POLL_INTERVAL, &process_state.supervisor_active) {
+            break;
+        }
+
+        let exited = {
+            let mut guard = process_state.child.lock().unwrap();
+            match guard.as_mut() {
+                Some(child) => match child.try_wait() {
+                    Ok(Some(status)) => {
+                        guard.take();
+                        Some(status)
+                    }
+                    Ok(None) => None,
+                    Err(e) => {
+                        eprintln!("Failed to poll Hyperion server: {}", e);
+                        None
+                    }
+                },
+                // No child means we're between a `stop_hyperion_server` call
+                // and the supervisor noticing; nothing to supervise yet.
+                None => None,
+            }
+        };
+
+        let Some(status) = exited else { continue };
+
+        if !process_state.supervisor_active.load(Ordering::SeqCst) {
+            // Stop was requested concurrently with the exit; don't restart.
+            break;
+        }
+
+        let reason = match status.code() {
+            Some(0) => "exited cleanly".to_string(),
+            Some(code) => format!("exited with status {code}"),
+            None => "killed by signal".to_string(),
+        };
+        println!("Hyperion server {reason}, restarting...");
+        process_state.record_exit(reason);
+
+        if last_restart.elapsed() >= STABLE_WINDOW {
+            backoff = INITIAL_BACKOFF;
+        }
+
+        // Keep retrying with growing backoff until a restart succeeds, so a
+        // failed spawn never leaves the child slot empty while supervised.
+        loop {
+            if !interruptible_sleep(backoff, &process_state.supervisor_active) {
+                return;
+            }
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+            last_restart = Instant::now();
+
+            match start_hyperion_server(&app_handle) {
+                Ok(child) => {
+                    process_state.set_child(child);
+                    process_state.restart_count.fetch_add(1, Ordering::SeqCst);
+                    break;
+                }
+                Err(e) => {
+                    eprintln!("Failed to restart Hyperion server: {}", e);
+                    process_state.record_exit(format!("restart failed: {e}"));
+                }
+            }
+        }
+    }
+}
+
+/// Stops the monitor before killing the child so a restart-in-flight can't
+/// race the shutdown and leave a new, unmanaged process behind. Joins the
+/// monitor thread so a subsequent `spawn_and_supervise` (e.g. from
+/// `restart_server`) never races the old thread's shutdown and leaks it. In
+/// remote mode we never spawned anything, so this is a deliberate no-op
+/// rather than an attempt to kill a process the app doesn't own.
+pub fn stop_hyperion_server(process: &HyperionProcess, config: &ServerConfig) {
+    if config.is_remote() {
+        return;
+    }
+
+    process.supervisor_active.store(false, Ordering::SeqCst);
+
+    if let Some(handle) = process.supervisor_handle.lock().unwrap().take() {
+        let _ = handle.join();
+    }
+
+    let mut child_opt = process.child.lock().unwrap();
+    if let Some(mut child) = child_opt.take() {
+        println!("Stopping Hyperion server (PID: {})...", child.id());
+        let _ = child.kill();
+        let _ = child.wait();
+        println!("Hyperion server stopped");
+    }
+}
+
+#[tauri::command]
+pub fn restart_server(app_handle: AppHandle) -> Result<(), String> {
+    let config: State<ServerConfig> = app_handle.state();
+    if config.is_remote() {
+        return Err("restart_server is unavailable in remote mode".to_string());
+    }
+    if cfg!(debug_assertions) {
+        return Err(
+            "restart_server is unavailable in development mode; the server is expected to be running externally"
+                .to_string(),
+        );
+    }
+
+    let process_state: State<HyperionProcess> = app_handle.state();
+    stop_hyperion_server(&process_state, &config);
+    spawn_and_supervise(app_handle.clone())
+}
+
+#[tauri::command]
+pub fn server_status(process_state: State<HyperionProcess>) -> serde_json::Value {
+    serde_json::json!({
+        "restartCount": process_state.restart_count.load(Ordering::SeqCst),
+        "lastExitReason": *process_state.last_exit_reason.lock().unwrap(),
+    })
+}
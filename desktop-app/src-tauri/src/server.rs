@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::env;
+use std::path::PathBuf;
+use std::process::{Child, Command};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+
+use crate::config::ServerConfig;
+use crate::mcp_client::{get_with_retry, McpClient, McpError};
+
+const READINESS_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+const READINESS_MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+pub fn get_hyper_binary_path(app_handle: &tauri::AppHandle) -> Result<PathBuf, String> {
+    // In development, use the binary from bin/
+    if cfg!(debug_assertions) {
+        let mut path = env::current_dir().map_err(|e| e.to_string())?;
+        path.pop(); // Go up from desktop-app/
+        path.push("bin");
+        path.push("hyper");
+        return Ok(path);
+    }
+
+    // In production, the binary is bundled with the app
+    let resource_path = app_handle
+        .path()
+        .resource_dir()
+        .map_err(|e| e.to_string())?;
+
+    let binary_name = if cfg!(target_os = "windows") {
+        "hyper.exe"
+    } else {
+        "hyper"
+    };
+
+    Ok(resource_path.join(binary_name))
+}
+
+pub fn get_env_path(app_handle: &tauri::AppHandle) -> Result<PathBuf, String> {
+    // In development, look for .env.hyper in bin/ directory
+    if cfg!(debug_assertions) {
+        let mut path = env::current_dir().map_err(|e| e.to_string())?;
+        path.pop(); // Go up from desktop-app/
+        path.push("bin");
+        path.push(".env.hyper");
+        return Ok(path);
+    }
+
+    // In production, look for .env.hyper in the resource directory
+    let resource_path = app_handle
+        .path()
+        .resource_dir()
+        .map_err(|e| e.to_string())?;
+
+    Ok(resource_path.join(".env.hyper"))
+}
+
+/// Parses `.env.hyper` into a key/value map. Missing or unreadable files
+/// just yield an empty map so callers can fall back to defaults.
+pub fn read_env_file(app_handle: &tauri::AppHandle) -> HashMap<String, String> {
+    let mut values = HashMap::new();
+
+    let Ok(path) = get_env_path(app_handle) else {
+        return values;
+    };
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return values;
+    };
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim().trim_matches('"').trim_matches('\'');
+            values.insert(key.trim().to_string(), value.to_string());
+        }
+    }
+
+    values
+}
+
+/// Spawns the `hyper` binary in HTTP mode. Used both for the initial launch
+/// and for supervisor-triggered restarts.
+pub fn start_hyperion_server(app_handle: &tauri::AppHandle) -> Result<Child, String> {
+    let binary_path = get_hyper_binary_path(app_handle)?;
+
+    if !binary_path.exists() {
+        return Err(format!("Hyperion binary not found at {:?}", binary_path));
+    }
+
+    println!("Starting Hyperion server from: {:?}", binary_path);
+
+    // Check if .env.hyper exists
+    let env_path = get_env_path(app_handle).ok();
+
+    let mut cmd = Command::new(&binary_path);
+    cmd.arg("--mode=http");
+
+    if let Some(env_file) = env_path {
+        if env_file.exists() {
+            println!("Using config file: {:?}", env_file);
+            // The binary will find .env.hyper automatically in its directory
+        } else {
+            println!("Warning: .env.hyper not found at {:?}", env_file);
+        }
+    }
+
+    let child = cmd
+        .stdout(std::process::Stdio::inherit())
+        .stderr(std::process::Stdio::inherit())
+        .spawn()
+        .map_err(|e| format!("Failed to start Hyperion server: {}", e))?;
+
+    println!("Hyperion server started with PID: {}", child.id());
+
+    Ok(child)
+}
+
+#[tauri::command]
+pub fn get_server_url(config: State<'_, ServerConfig>) -> String {
+    config.ui_url()
+}
+
+#[tauri::command]
+pub async fn check_server_health(
+    client: State<'_, McpClient>,
+    config: State<'_, ServerConfig>,
+) -> Result<String, String> {
+    check_health(&client, &config).await.map_err(|e| e.message)
+}
+
+/// Routed through the shared `McpClient` so the health check carries the
+/// same bearer auth and bounded timeout as every other coordinator request,
+/// instead of a bare unauthenticated, unbounded `reqwest::Client::new()`.
+async fn check_health(client: &McpClient, config: &ServerConfig) -> Result<String, McpError> {
+    let response = get_with_retry(client, &config.health_url()).await?;
+    if response.status().is_success() {
+        Ok("healthy".to_string())
+    } else {
+        Err(McpError {
+            code: format!("http_{}", response.status().as_u16()),
+            message: format!("Server returned status: {}", response.status()),
+            retry_after: None,
+        })
+    }
+}
+
+#[derive(Clone, Serialize)]
+struct ReadinessProgress {
+    attempt: u32,
+    #[serde(rename = "elapsedMs")]
+    elapsed_ms: u128,
+}
+
+#[derive(Clone, Serialize)]
+struct ReadinessFailure {
+    attempt: u32,
+    #[serde(rename = "elapsedMs")]
+    elapsed_ms: u128,
+    error: String,
+}
+
+/// Polls `check_server_health` with exponential backoff and emits
+/// `hyperion://starting`, `hyperion://ready` and `hyperion://failed` so the
+/// frontend can show real startup progress instead of waiting out a fixed
+/// sleep that's either too slow or too short.
+pub fn spawn_readiness_watcher(app_handle: AppHandle, config: ServerConfig) {
+    tauri::async_runtime::spawn(async move {
+        let client: State<McpClient> = app_handle.state();
+        let start = Instant::now();
+        let mut backoff = READINESS_INITIAL_BACKOFF;
+        let mut attempt: u32 = 0;
+
+        let _ = app_handle.emit_all(
+            "hyperion://starting",
+            ReadinessProgress { attempt, elapsed_ms: 0 },
+        );
+
+        loop {
+            attempt += 1;
+            match check_health(&client, &config).await {
+                Ok(_) => {
+                    let _ = app_handle.emit_all(
+                        "hyperion://ready",
+                        ReadinessProgress {
+                            attempt,
+                            elapsed_ms: start.elapsed().as_millis(),
+                        },
+                    );
+                    return;
+                }
+                Err(error) => {
+                    if start.elapsed() >= config.readiness_timeout {
+                        let _ = app_handle.emit_all(
+                            "hyperion://failed",
+                            ReadinessFailure {
+                                attempt,
+                                elapsed_ms: start.elapsed().as_millis(),
+                                error: error.message,
+                            },
+                        );
+                        return;
+                    }
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(READINESS_MAX_BACKOFF);
+                }
+            }
+        }
+    });
+}